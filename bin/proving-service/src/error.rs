@@ -0,0 +1,29 @@
+use miden_objects::utils::DeserializationError;
+use thiserror::Error;
+
+// PROVING SERVICE ERROR
+// ================================================================================================
+
+/// Errors surfaced by the proving service proxy.
+#[derive(Debug, Error)]
+pub enum ProvingServiceError {
+    /// A worker address could not be parsed into a valid URI.
+    #[error("invalid worker URI: {0}")]
+    InvalidURI(String),
+
+    /// A connection to a worker could not be established.
+    #[error("failed to connect to worker {0}: {1}")]
+    ConnectionFailed(String, String),
+
+    /// No worker was available and healthy to take the job.
+    #[error("no available workers to prove the transaction")]
+    NoAvailableWorkers,
+
+    /// A worker rejected or failed to prove the job.
+    #[error("worker {0} failed to prove the transaction: {1}")]
+    WorkerFailed(String, String),
+
+    /// The proven transaction returned by a worker could not be deserialized.
+    #[error("failed to deserialize the proven transaction: {0}")]
+    DeserializationFailed(#[source] DeserializationError),
+}
@@ -0,0 +1,301 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use miden_objects::{accounts::AccountId, transaction::TransactionWitness};
+use tracing::debug;
+
+// SCORING
+// ================================================================================================
+
+/// Penalty subtracted from every queued request of a submitter each time one of its witnesses
+/// fails proving, so well-behaved callers are served ahead of repeatedly-failing ones.
+const FAILURE_PENALTY: i64 = 1_000;
+
+/// Key used to order the ready set. Entries sort ascending by score, then by arrival sequence with
+/// earlier arrivals ordered *later* so that, among equal scores, the oldest request is the highest
+/// priority (the last entry in the map) while the newest is the first to be evicted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct ScoreKey {
+    score: i64,
+    arrival: core::cmp::Reverse<u64>,
+}
+
+// PENDING REQUEST
+// ================================================================================================
+
+/// A proving request waiting in the queue, carrying a payload `T` (a [TransactionWitness] in
+/// production).
+///
+/// Requests are keyed by their [AccountId] both for the per-account capacity cap and so that a
+/// submitter's whole backlog can be penalized together when one of its witnesses fails.
+#[derive(Debug)]
+pub struct PendingRequest<T> {
+    pub payload: T,
+    account_id: AccountId,
+    base_score: i64,
+    arrival: u64,
+}
+
+impl<T> PendingRequest<T> {
+    fn key(&self) -> ScoreKey {
+        ScoreKey { score: self.base_score, arrival: core::cmp::Reverse(self.arrival) }
+    }
+
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    pub fn score(&self) -> i64 {
+        self.base_score
+    }
+}
+
+// SCORED QUEUE
+// ================================================================================================
+
+/// Scored, bounded pending-request queue keyed by submitting [AccountId].
+///
+/// Incoming requests are ordered in a ready set by score; the highest-scored request is pulled via
+/// [next_ready](Self::next_ready) whenever a worker becomes free. The queue enforces a hard cap on
+/// total entries and a per-account cap so one submitter cannot starve others, evicting the
+/// lowest-scored entry rather than rejecting the newest when full.
+pub struct ScoredQueue<T> {
+    ready: BTreeMap<ScoreKey, PendingRequest<T>>,
+    /// Sequence number of each in-queue request, grouped by submitting account.
+    by_account: HashMap<AccountId, HashSet<u64>>,
+    /// Locates an entry's current key from its arrival sequence (keys move on penalization).
+    keys: HashMap<u64, ScoreKey>,
+    capacity: usize,
+    per_account_cap: usize,
+    next_arrival: u64,
+}
+
+impl<T> ScoredQueue<T> {
+    /// Creates a queue holding at most `capacity` entries, with any single account limited to
+    /// `per_account_fraction` of that capacity (rounded up, at least one).
+    pub fn new(capacity: usize, per_account_fraction: f64) -> Self {
+        let per_account_cap = ((capacity as f64) * per_account_fraction).ceil() as usize;
+        Self {
+            ready: BTreeMap::new(),
+            by_account: HashMap::new(),
+            keys: HashMap::new(),
+            capacity: capacity.max(1),
+            per_account_cap: per_account_cap.max(1),
+            next_arrival: 0,
+        }
+    }
+
+    /// Submits a payload from `account_id` with a caller-supplied score (derived from its
+    /// fee/priority). The final ordering also folds in arrival time so older requests win ties.
+    ///
+    /// Returns `true` if the request was admitted. A request is rejected only when the queue (or the
+    /// account's share of it) is full and its score does not beat the lowest-scored entry that could
+    /// be evicted for it.
+    pub fn submit(&mut self, account_id: AccountId, payload: T, score: i64) -> bool {
+        let arrival = self.next_arrival;
+        self.next_arrival += 1;
+
+        let request = PendingRequest { payload, account_id, base_score: score, arrival };
+
+        // enforce the per-account cap first: a submitter over its cap may only displace its own
+        // lowest-scored entry, never someone else's.
+        if self.account_count(account_id) >= self.per_account_cap {
+            match self.lowest_of_account(account_id) {
+                Some(lowest) if lowest < request.key() => self.remove_key(lowest),
+                _ => return false,
+            };
+        }
+
+        // enforce the global cap: evict the lowest-scored entry across all accounts.
+        if self.ready.len() >= self.capacity {
+            match self.ready.keys().next().copied() {
+                Some(lowest) if lowest < request.key() => self.remove_key(lowest),
+                _ => return false,
+            };
+        }
+
+        self.insert(request);
+        true
+    }
+
+    /// Removes and returns the highest-scored ready request, or `None` if the queue is empty.
+    pub fn next_ready(&mut self) -> Option<PendingRequest<T>> {
+        let key = *self.ready.keys().next_back()?;
+        self.remove_key(key)
+    }
+
+    /// Penalizes every queued request of `account_id`, dropping their scores so better-behaved
+    /// submitters are served first. Called when one of the account's witnesses fails proving.
+    pub fn penalize(&mut self, account_id: AccountId) {
+        let arrivals: Vec<u64> =
+            self.by_account.get(&account_id).map(|s| s.iter().copied().collect()).unwrap_or_default();
+
+        for arrival in arrivals {
+            if let Some(old_key) = self.keys.get(&arrival).copied() {
+                let mut request = self.ready.remove(&old_key).expect("key must map to an entry");
+                request.base_score -= FAILURE_PENALTY;
+                self.keys.insert(arrival, request.key());
+                self.ready.insert(request.key(), request);
+            }
+        }
+        debug!("penalized queued requests for account {}", account_id);
+    }
+
+    /// Number of queued requests from the given account.
+    pub fn account_count(&self, account_id: AccountId) -> usize {
+        self.by_account.get(&account_id).map_or(0, HashSet::len)
+    }
+
+    /// Total number of queued requests.
+    pub fn len(&self) -> usize {
+        self.ready.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ready.is_empty()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn insert(&mut self, request: PendingRequest<T>) {
+        let key = request.key();
+        self.by_account.entry(request.account_id).or_default().insert(request.arrival);
+        self.keys.insert(request.arrival, key);
+        self.ready.insert(key, request);
+    }
+
+    fn remove_key(&mut self, key: ScoreKey) -> Option<PendingRequest<T>> {
+        let request = self.ready.remove(&key)?;
+        self.keys.remove(&request.arrival);
+        if let Some(arrivals) = self.by_account.get_mut(&request.account_id) {
+            arrivals.remove(&request.arrival);
+            if arrivals.is_empty() {
+                self.by_account.remove(&request.account_id);
+            }
+        }
+        Some(request)
+    }
+
+    fn lowest_of_account(&self, account_id: AccountId) -> Option<ScoreKey> {
+        self.by_account
+            .get(&account_id)?
+            .iter()
+            .filter_map(|arrival| self.keys.get(arrival).copied())
+            .min()
+    }
+}
+
+// PRIORITY QUEUE
+// ================================================================================================
+
+/// The pending-request queue sitting in front of the worker pool: a [ScoredQueue] of
+/// [TransactionWitness]es keyed by the witness's native account.
+pub struct PriorityQueue {
+    inner: ScoredQueue<TransactionWitness>,
+}
+
+impl PriorityQueue {
+    /// Creates a queue holding at most `capacity` witnesses, capping any single account at
+    /// `per_account_fraction` of that capacity.
+    pub fn new(capacity: usize, per_account_fraction: f64) -> Self {
+        Self { inner: ScoredQueue::new(capacity, per_account_fraction) }
+    }
+
+    /// Submits a witness with a caller-supplied score; see [ScoredQueue::submit].
+    pub fn submit(&mut self, witness: TransactionWitness, score: i64) -> bool {
+        let account_id = witness.tx_inputs.account().id();
+        self.inner.submit(account_id, witness, score)
+    }
+
+    /// Pulls the highest-scored ready witness; see [ScoredQueue::next_ready].
+    pub fn next_ready(&mut self) -> Option<TransactionWitness> {
+        self.inner.next_ready().map(|request| request.payload)
+    }
+
+    /// Penalizes every queued request of the given account; see [ScoredQueue::penalize].
+    pub fn penalize(&mut self, account_id: AccountId) {
+        self.inner.penalize(account_id);
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proxy::test_support::account;
+
+    #[test]
+    fn next_ready_returns_highest_score_then_oldest() {
+        let mut queue = ScoredQueue::new(8, 1.0);
+        queue.submit(account(1), "low", 1);
+        queue.submit(account(2), "high", 10);
+        queue.submit(account(3), "mid-old", 5);
+        queue.submit(account(1), "mid-new", 5);
+
+        // highest score first.
+        assert_eq!(queue.next_ready().unwrap().payload, "high");
+        // ties broken by arrival: the older entry wins.
+        assert_eq!(queue.next_ready().unwrap().payload, "mid-old");
+        assert_eq!(queue.next_ready().unwrap().payload, "mid-new");
+        assert_eq!(queue.next_ready().unwrap().payload, "low");
+        assert!(queue.next_ready().is_none());
+    }
+
+    #[test]
+    fn full_queue_evicts_lowest_score_not_newest() {
+        let mut queue = ScoredQueue::new(2, 1.0);
+        assert!(queue.submit(account(1), "a", 1));
+        assert!(queue.submit(account(2), "b", 2));
+
+        // a higher-scored arrival displaces the lowest-scored entry.
+        assert!(queue.submit(account(3), "c", 3));
+        assert_eq!(queue.len(), 2);
+
+        // a lower-scored arrival than everything queued is rejected rather than evicting anyone.
+        assert!(!queue.submit(account(3), "d", 0));
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(queue.next_ready().unwrap().payload, "c");
+        assert_eq!(queue.next_ready().unwrap().payload, "b");
+    }
+
+    #[test]
+    fn per_account_cap_limits_single_submitter() {
+        // capacity 10, 20% per-account cap => at most 2 entries per account.
+        let mut queue = ScoredQueue::new(10, 0.2);
+        assert!(queue.submit(account(1), "a1", 1));
+        assert!(queue.submit(account(1), "a2", 2));
+
+        // a third entry from the same account only lands if it beats the account's lowest entry.
+        assert!(!queue.submit(account(1), "a-low", 0));
+        assert!(queue.submit(account(1), "a-high", 5));
+        assert_eq!(queue.account_count(account(1)), 2);
+
+        // a different account is unaffected by the first account's cap.
+        assert!(queue.submit(account(2), "b", 1));
+        assert_eq!(queue.account_count(account(2)), 1);
+    }
+
+    #[test]
+    fn penalization_drops_a_submitters_requests_below_others() {
+        let mut queue = ScoredQueue::new(8, 1.0);
+        queue.submit(account(1), "bad", 100);
+        queue.submit(account(2), "good", 50);
+
+        // before penalization the high-fee bad actor is served first.
+        // penalizing drops every request of account(1) below the well-behaved submitter.
+        queue.penalize(account(1));
+        assert_eq!(queue.next_ready().unwrap().payload, "good");
+        assert_eq!(queue.next_ready().unwrap().payload, "bad");
+    }
+}
@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use miden_objects::{
+    transaction::{ProvenTransaction, TransactionWitness},
+    utils::{Deserializable, Serializable},
+};
+use miden_tx::{TransactionProver, TransactionProverError};
+use tokio::sync::Mutex;
+use tracing::{error, warn};
+use winter_maybe_async::{maybe_async, maybe_async_trait};
+
+use super::worker::Worker;
+use crate::{
+    error::ProvingServiceError,
+    generated::api::{ProofType, ProvingRequest, api_client::ApiClient},
+};
+
+// REMOTE TRANSACTION PROVER
+// ================================================================================================
+
+/// Default number of alternative workers a job is retried on before giving up.
+const DEFAULT_MAX_RETRIES: usize = 3;
+
+/// A [TransactionProver] that proves transactions on a remote worker pool over gRPC instead of
+/// locally.
+///
+/// Because it implements the same [TransactionProver] trait, callers can swap a
+/// `LocalTransactionProver` for a [RemoteTransactionProver] transparently. Each job serializes the
+/// [TransactionWitness], selects an available and healthy [Worker] from the pool, and ships the
+/// work to it; on a worker error or disconnect the job is retried on a different worker up to
+/// `max_retries` times before a [ProvingServiceError] is surfaced.
+#[derive(Clone)]
+pub struct RemoteTransactionProver {
+    workers: Arc<Mutex<Vec<Worker>>>,
+    max_retries: usize,
+}
+
+impl RemoteTransactionProver {
+    /// Creates a new remote prover over the given worker pool.
+    pub fn new(workers: Vec<Worker>) -> Self {
+        Self {
+            workers: Arc::new(Mutex::new(workers)),
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// Sets the maximum number of alternative workers a job is retried on before failing.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Proves many witnesses at once, fanning them out across workers concurrently and awaiting all
+    /// results, so a client can prove a batch of transactions in parallel.
+    ///
+    /// All jobs are awaited to completion even if some fail; the returned vector preserves the order
+    /// of `witnesses`. If any job failed, the first error (in submission order) is returned after
+    /// every in-flight job has resolved, so no work is cancelled mid-flight.
+    pub async fn submit_batch(
+        &self,
+        witnesses: Vec<TransactionWitness>,
+    ) -> Result<Vec<ProvenTransaction>, ProvingServiceError> {
+        let futures = witnesses.into_iter().map(|witness| self.prove_remote(witness));
+        let results = futures::future::join_all(futures).await;
+        results.into_iter().collect()
+    }
+
+    /// Serializes `witness`, picks an available and healthy worker, and retries on alternative
+    /// workers until one succeeds or the retry limit is reached.
+    async fn prove_remote(
+        &self,
+        witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, ProvingServiceError> {
+        let payload = witness.to_bytes();
+
+        let mut tried = Vec::new();
+        let mut last_error = None;
+        for _ in 0..=self.max_retries {
+            let Some(worker) = self.select_backend(&tried).await else {
+                break;
+            };
+
+            match Self::prove_on_worker(&worker, payload.clone()).await {
+                Ok(tx) => {
+                    self.record_success(&worker.address()).await;
+                    return Ok(tx);
+                },
+                Err(err) => {
+                    warn!("proving on worker {} failed, retrying: {}", worker.address(), err);
+                    tried.push(worker.address());
+                    last_error = Some(err);
+                },
+            }
+        }
+
+        Err(last_error.unwrap_or(ProvingServiceError::NoAvailableWorkers))
+    }
+
+    /// Records a successful proof against the worker at `address` so its `last_success` timestamp
+    /// feeds into future [WorkerHealth](super::worker::WorkerHealth) reports.
+    async fn record_success(&self, address: &str) {
+        let mut workers = self.workers.lock().await;
+        if let Some(worker) = workers.iter_mut().find(|w| w.address() == address) {
+            worker.record_success();
+        }
+    }
+
+    /// Selects the least-loaded ready worker that has not already been tried for this job, using
+    /// each worker's [WorkerHealth](super::worker::WorkerHealth) report (load and clock drift)
+    /// rather than a bare serving flag.
+    async fn select_backend(&self, exclude: &[String]) -> Option<Worker> {
+        let mut workers = self.workers.lock().await;
+        let mut best: Option<(usize, u32)> = None;
+        for (idx, worker) in workers.iter_mut().enumerate() {
+            if exclude.contains(&worker.address()) || !worker.is_available() {
+                continue;
+            }
+            let health = worker.health_report().await;
+            if !health.is_ready() {
+                continue;
+            }
+            if best.map_or(true, |(_, load)| health.load() < load) {
+                best = Some((idx, health.load()));
+            }
+        }
+        best.map(|(idx, _)| workers[idx].clone())
+    }
+
+    /// Ships a serialized witness to a single worker over gRPC and deserializes the response.
+    async fn prove_on_worker(
+        worker: &Worker,
+        payload: Vec<u8>,
+    ) -> Result<ProvenTransaction, ProvingServiceError> {
+        let mut client = ApiClient::connect(format!("http://{}", worker.address()))
+            .await
+            .map_err(|err| ProvingServiceError::ConnectionFailed(worker.address(), err.to_string()))?;
+
+        let request = ProvingRequest { proof_type: ProofType::Transaction as i32, payload };
+
+        let response = client
+            .prove(request)
+            .await
+            .map_err(|status| ProvingServiceError::WorkerFailed(worker.address(), status.to_string()))?
+            .into_inner();
+
+        ProvenTransaction::read_from_bytes(&response.payload)
+            .map_err(ProvingServiceError::DeserializationFailed)
+    }
+}
+
+#[maybe_async_trait]
+impl TransactionProver for RemoteTransactionProver {
+    #[maybe_async]
+    fn prove(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.prove_remote(tx_witness).await.map_err(|err| {
+            error!("remote proving failed: {}", err);
+            TransactionProverError::other(err.to_string())
+        })
+    }
+}
@@ -0,0 +1,369 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crossbeam_channel::{Receiver, Sender, TryRecvError, TrySendError, bounded};
+use miden_objects::{accounts::AccountId, transaction::TransactionWitness};
+use miden_tx::ProvenTransaction;
+use tracing::{debug, warn};
+
+use crate::error::ProvingServiceError;
+
+// JOB TYPES
+// ================================================================================================
+
+/// Monotonically increasing identifier assigned to each scheduled proving job.
+pub type JobId = u64;
+
+/// Work handed to a worker: a proving job identified by [JobId] together with the witness to prove.
+#[derive(Debug)]
+pub struct ConsumeWork {
+    pub job_id: JobId,
+    pub witness: TransactionWitness,
+}
+
+/// Result handed back by a worker once it finished (or failed) a proving job.
+#[derive(Debug)]
+pub struct FinishedConsumeWork {
+    pub job_id: JobId,
+    pub result: Result<ProvenTransaction, ProvingServiceError>,
+}
+
+/// Handle returned to the caller when a witness is scheduled.
+///
+/// The handle only carries the [JobId]; finished results are drained centrally from the scheduler
+/// via [Scheduler::try_recv_finished] and matched back to the handle by the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobHandle {
+    job_id: JobId,
+}
+
+impl JobHandle {
+    pub fn job_id(&self) -> JobId {
+        self.job_id
+    }
+}
+
+// DISPATCHER
+// ================================================================================================
+
+/// Account-aware dispatch core shared by the [Scheduler].
+///
+/// The dispatcher owns the per-worker work channels and tracks which [AccountId]s are currently
+/// "held" by an in-flight job. A queued job is only dispatched once none of the accounts it touches
+/// are held by another worker; independent accounts therefore run in parallel while same-account
+/// jobs serialize in submission order.
+///
+/// It is generic over the work message `I` so the locking behaviour can be exercised without
+/// constructing real witnesses.
+struct Dispatcher<I> {
+    /// Per-worker sending end of the work channel, paired with whether that worker is busy.
+    workers: Vec<(Sender<I>, bool)>,
+    /// Accounts currently locked by an in-flight job, mapped to the worker index holding them.
+    account_locks: HashMap<AccountId, usize>,
+    /// Accounts of each in-flight job, kept so the locks can be released on completion.
+    in_flight: HashMap<JobId, Vec<AccountId>>,
+    /// Jobs waiting to be dispatched, in submission order.
+    pending: VecDeque<(JobId, Vec<AccountId>, I)>,
+    /// Jobs whose worker channel was found disconnected at dispatch time, paired with the worker
+    /// index; drained by the owner so it can surface a failed result instead of losing the job.
+    failed: Vec<(JobId, usize)>,
+}
+
+impl<I> Dispatcher<I> {
+    fn new(senders: Vec<Sender<I>>) -> Self {
+        Self {
+            workers: senders.into_iter().map(|tx| (tx, false)).collect(),
+            account_locks: HashMap::new(),
+            in_flight: HashMap::new(),
+            pending: VecDeque::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    /// Takes the jobs that failed to dispatch since the last call, so the owner can report them.
+    fn take_failed(&mut self) -> Vec<(JobId, usize)> {
+        core::mem::take(&mut self.failed)
+    }
+
+    /// Queues a job and dispatches everything that the current locks allow.
+    fn schedule(&mut self, job_id: JobId, accounts: Vec<AccountId>, item: I) {
+        self.pending.push_back((job_id, accounts, item));
+        self.dispatch();
+    }
+
+    /// Releases the account locks held by the given job, frees its worker, and dispatches any jobs
+    /// the freed locks unblock.
+    fn release(&mut self, job_id: JobId) {
+        self.unlock(job_id);
+        self.dispatch();
+    }
+
+    /// Releases the account locks held by the given job and frees its worker, without triggering a
+    /// dispatch pass. Used both by [release](Self::release) and when unwinding a failed dispatch from
+    /// inside [dispatch](Self::dispatch), where re-entering dispatch would recurse.
+    fn unlock(&mut self, job_id: JobId) {
+        if let Some(accounts) = self.in_flight.remove(&job_id) {
+            for account in accounts {
+                if let Some(idx) = self.account_locks.remove(&account) {
+                    self.workers[idx].1 = false;
+                }
+            }
+        }
+    }
+
+    /// Walks the pending queue in submission order and dispatches every job that can run without
+    /// contending for a locked account, stopping early once no worker is free.
+    fn dispatch(&mut self) {
+        // accounts claimed by an earlier still-pending job in this pass; a later same-account job
+        // must wait behind it to preserve submission order.
+        let mut claimed: HashMap<AccountId, ()> = HashMap::new();
+        let mut requeue: VecDeque<(JobId, Vec<AccountId>, I)> = VecDeque::new();
+
+        while let Some((job_id, accounts, item)) = self.pending.pop_front() {
+            let contended = accounts
+                .iter()
+                .any(|a| self.account_locks.contains_key(a) || claimed.contains_key(a));
+
+            if contended {
+                for account in &accounts {
+                    claimed.insert(*account, ());
+                }
+                requeue.push_back((job_id, accounts, item));
+                continue;
+            }
+
+            match self.workers.iter().position(|(_, busy)| !busy) {
+                Some(idx) => {
+                    for account in &accounts {
+                        self.account_locks.insert(*account, idx);
+                    }
+                    self.in_flight.insert(job_id, accounts);
+                    self.workers[idx].1 = true;
+
+                    if self.workers[idx].0.send(item).is_err() {
+                        // the worker died: undo its locks in place (re-entering dispatch here would
+                        // recurse mid-pass) and record the job so the owner can fail it instead of
+                        // silently dropping the witness.
+                        warn!("worker {} work channel disconnected; failing job {}", idx, job_id);
+                        self.unlock(job_id);
+                        self.failed.push((job_id, idx));
+                    } else {
+                        debug!("dispatched job {} to worker {}", job_id, idx);
+                    }
+                },
+                None => {
+                    // no free worker: keep this and the rest of the queue for the next dispatch.
+                    requeue.push_back((job_id, accounts, item));
+                    break;
+                },
+            }
+        }
+
+        requeue.append(&mut self.pending);
+        self.pending = requeue;
+    }
+}
+
+// SCHEDULER
+// ================================================================================================
+
+/// Account-aware proving scheduler.
+///
+/// The scheduler distributes [TransactionWitness] proving jobs across a pool of workers over
+/// bounded channels, in the spirit of a banking-stage style worker model: it sends [ConsumeWork] to
+/// per-worker receivers and collects [FinishedConsumeWork] back over a shared channel. Two
+/// witnesses mutating the same [AccountId] are never in flight on different workers at once, so the
+/// deltas they produce can be applied in submission order without conflict.
+pub struct Scheduler {
+    dispatcher: Dispatcher<ConsumeWork>,
+    /// Sending end of the finished channel, retained so jobs that could not be dispatched (their
+    /// worker channel was disconnected) can be reported back as failures rather than lost.
+    finished_tx: Sender<FinishedConsumeWork>,
+    /// Receiver shared by all workers over which finished jobs arrive.
+    finished_rx: Receiver<FinishedConsumeWork>,
+    next_job_id: AtomicU64,
+}
+
+impl Scheduler {
+    /// Creates a scheduler driving `num_workers` workers.
+    ///
+    /// Each per-worker work channel is bounded by `work_channel_capacity`. The caller is expected to
+    /// drive the returned work receivers (one per worker) and feed results back over the returned
+    /// finished sender.
+    pub fn new(
+        num_workers: usize,
+        work_channel_capacity: usize,
+    ) -> (Self, Vec<Receiver<ConsumeWork>>, Sender<FinishedConsumeWork>) {
+        let (finished_tx, finished_rx) =
+            bounded(work_channel_capacity.max(1) * num_workers.max(1));
+
+        let mut senders = Vec::with_capacity(num_workers);
+        let mut receivers = Vec::with_capacity(num_workers);
+        for _ in 0..num_workers {
+            let (work_tx, work_rx) = bounded::<ConsumeWork>(work_channel_capacity);
+            senders.push(work_tx);
+            receivers.push(work_rx);
+        }
+
+        let scheduler = Self {
+            dispatcher: Dispatcher::new(senders),
+            finished_tx: finished_tx.clone(),
+            finished_rx,
+            next_job_id: AtomicU64::new(0),
+        };
+
+        (scheduler, receivers, finished_tx)
+    }
+
+    /// Schedules a witness for proving and returns a [JobHandle] identifying the job.
+    ///
+    /// The job is dispatched immediately if a free worker can take it without violating account
+    /// locking; otherwise it waits until a holding worker frees the contended account.
+    pub fn schedule(&mut self, witness: TransactionWitness) -> JobHandle {
+        let job_id = self.next_job_id.fetch_add(1, Ordering::Relaxed);
+        let accounts = accounts_touched(&witness);
+        self.dispatcher.schedule(job_id, accounts, ConsumeWork { job_id, witness });
+        self.report_failed_dispatches();
+        JobHandle { job_id }
+    }
+
+    /// Surfaces any jobs the dispatcher could not hand to a worker (the worker channel was
+    /// disconnected) as failed results on the finished channel, so the caller observes a failure
+    /// instead of a job that never completes.
+    fn report_failed_dispatches(&mut self) {
+        for (job_id, idx) in self.dispatcher.take_failed() {
+            let result = Err(ProvingServiceError::WorkerFailed(
+                format!("worker {idx}"),
+                "work channel disconnected before dispatch".to_string(),
+            ));
+            // the finished channel is sized for the whole pool, but if it is momentarily full the
+            // failure is kept and reported again once a finished result has been drained.
+            if let Err(TrySendError::Full(_)) =
+                self.finished_tx.try_send(FinishedConsumeWork { job_id, result })
+            {
+                self.dispatcher.failed.push((job_id, idx));
+            }
+        }
+    }
+
+    /// Drains the next finished result, releasing the worker and its account locks and dispatching
+    /// any jobs that the freed locks unblock.
+    ///
+    /// Returns `None` if no finished result is currently available.
+    pub fn try_recv_finished(&mut self) -> Option<FinishedConsumeWork> {
+        match self.finished_rx.try_recv() {
+            Ok(finished) => {
+                self.dispatcher.release(finished.job_id);
+                self.report_failed_dispatches();
+                Some(finished)
+            },
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                warn!("proving worker finished channel disconnected");
+                None
+            },
+        }
+    }
+}
+
+/// Returns the set of accounts a witness mutates, which the scheduler must lock before dispatch.
+///
+/// A transaction mutates its native account; the account id is returned in a [Vec] so the locking
+/// logic can extend to foreign-account inputs without changing the scheduler.
+fn accounts_touched(witness: &TransactionWitness) -> Vec<AccountId> {
+    vec![witness.tx_inputs.account().id()]
+}
+
+// WORKER LOOP
+// ================================================================================================
+
+/// Drives a single worker's receiving end: pulls [ConsumeWork], proves it with `prove`, and sends
+/// the [FinishedConsumeWork] back over the shared channel until the work channel disconnects.
+pub fn spawn_worker<F>(
+    work_rx: Receiver<ConsumeWork>,
+    finished_tx: Sender<FinishedConsumeWork>,
+    prove: F,
+) where
+    F: Fn(TransactionWitness) -> Result<ProvenTransaction, ProvingServiceError>,
+{
+    while let Ok(ConsumeWork { job_id, witness }) = work_rx.recv() {
+        let result = prove(witness);
+        if finished_tx.send(FinishedConsumeWork { job_id, result }).is_err() {
+            break;
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proxy::test_support::account;
+
+    /// Collects the job ids received so far on a worker channel without blocking.
+    fn drained(rx: &Receiver<JobId>) -> Vec<JobId> {
+        let mut ids = Vec::new();
+        while let Ok(job_id) = rx.try_recv() {
+            ids.push(job_id);
+        }
+        ids
+    }
+
+    #[test]
+    fn independent_accounts_dispatch_in_parallel() {
+        let (tx0, rx0) = bounded(8);
+        let (tx1, rx1) = bounded(8);
+        let mut dispatcher = Dispatcher::new(vec![tx0, tx1]);
+
+        let (a, b) = (account(1), account(2));
+        dispatcher.schedule(0, vec![a], 0);
+        dispatcher.schedule(1, vec![b], 1);
+
+        // both workers pick up a job immediately since the accounts are independent.
+        assert_eq!(drained(&rx0), vec![0]);
+        assert_eq!(drained(&rx1), vec![1]);
+    }
+
+    #[test]
+    fn same_account_jobs_serialize_in_submission_order() {
+        let (tx0, rx0) = bounded(8);
+        let (tx1, rx1) = bounded(8);
+        let mut dispatcher = Dispatcher::new(vec![tx0, tx1]);
+
+        let a = account(1);
+        dispatcher.schedule(0, vec![a], 0);
+        dispatcher.schedule(1, vec![a], 1);
+        dispatcher.schedule(2, vec![a], 2);
+
+        // only the first same-account job is dispatched; the rest wait behind the lock even though
+        // a second worker is free.
+        assert_eq!(drained(&rx0), vec![0]);
+        assert!(drained(&rx1).is_empty());
+
+        // completing job 0 frees the account and lets job 1 through, still before job 2.
+        dispatcher.release(0);
+        assert_eq!(drained(&rx0), vec![1]);
+        assert!(drained(&rx1).is_empty());
+
+        dispatcher.release(1);
+        assert_eq!(drained(&rx0), vec![2]);
+    }
+
+    #[test]
+    fn jobs_queue_when_all_workers_busy() {
+        let (tx0, rx0) = bounded(8);
+        let mut dispatcher = Dispatcher::new(vec![tx0]);
+
+        dispatcher.schedule(0, vec![account(1)], 0);
+        dispatcher.schedule(1, vec![account(2)], 1);
+
+        // only one worker, so the second independent job waits until the first finishes.
+        assert_eq!(drained(&rx0), vec![0]);
+        dispatcher.release(0);
+        assert_eq!(drained(&rx0), vec![1]);
+    }
+}
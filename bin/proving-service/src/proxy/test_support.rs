@@ -0,0 +1,19 @@
+//! Shared fixtures for the proxy unit tests.
+
+use miden_objects::{
+    accounts::AccountId,
+    testing::account_id::{
+        ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE, ACCOUNT_ID_SENDER,
+    },
+};
+
+/// Returns one of a few distinct, valid test account ids keyed by a small index.
+pub(crate) fn account(id: u8) -> AccountId {
+    let raw = match id {
+        1 => ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
+        2 => ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        _ => ACCOUNT_ID_SENDER,
+    };
+    AccountId::try_from(raw).unwrap()
+}
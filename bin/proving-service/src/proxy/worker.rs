@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use pingora::lb::Backend;
 use tonic::transport::Channel;
@@ -8,7 +8,14 @@ use tonic_health::pb::{
 use tracing::error;
 
 use super::health_check::create_health_check_client;
-use crate::error::ProvingServiceError;
+use crate::{
+    error::ProvingServiceError,
+    generated::api::{StatusRequest, api_client::ApiClient},
+};
+
+/// Maximum clock drift tolerated between a worker and the coordinator. Because proving is sensitive
+/// to block height, a worker whose clock drifts beyond this is treated as unavailable.
+const MAX_CLOCK_DRIFT: Duration = Duration::from_secs(5);
 
 // WORKER
 // ================================================================================================
@@ -22,6 +29,41 @@ pub struct Worker {
     backend: Backend,
     health_check_client: HealthClient<Channel>,
     is_available: bool,
+    last_success: Option<Instant>,
+}
+
+// WORKER HEALTH
+// ================================================================================================
+
+/// A readiness report for a worker, gathered by [Worker::health_report].
+///
+/// Beyond the gRPC serving status, this captures how loaded the worker is, when it last completed a
+/// job, and how far its clock has drifted from the coordinator's, so the scheduler can prefer
+/// least-loaded workers and steer clear of clock-skewed ones.
+#[derive(Debug, Clone)]
+pub struct WorkerHealth {
+    /// Whether the worker reported a gRPC [ServingStatus::Serving] status.
+    pub is_serving: bool,
+    /// Number of jobs currently executing on the worker.
+    pub in_flight: u32,
+    /// Number of jobs queued on the worker and not yet started.
+    pub queued: u32,
+    /// Instant at which the coordinator last observed this worker finish a job successfully.
+    pub last_success: Option<Instant>,
+    /// Absolute difference between the worker's reported time and the coordinator's at check time.
+    pub clock_drift: Duration,
+}
+
+impl WorkerHealth {
+    /// Total number of jobs the worker is responsible for (executing plus queued).
+    pub fn load(&self) -> u32 {
+        self.in_flight.saturating_add(self.queued)
+    }
+
+    /// Whether the worker is ready to take new work: serving and within the clock-drift threshold.
+    pub fn is_ready(&self) -> bool {
+        self.is_serving && self.clock_drift <= MAX_CLOCK_DRIFT
+    }
 }
 
 impl Worker {
@@ -43,6 +85,7 @@ impl Worker {
             backend: worker,
             is_available: true,
             health_check_client,
+            last_success: None,
         })
     }
 
@@ -64,6 +107,72 @@ impl Worker {
         }
     }
 
+    /// Gathers a richer readiness report for the worker.
+    ///
+    /// In addition to the gRPC serving status, this queries the worker's status endpoint for its
+    /// current load and reported time, and estimates clock drift by comparing that time against the
+    /// coordinator's at check time. A worker that fails to answer its status query is reported as
+    /// not ready and maximally loaded, so the scheduler steers work away from it rather than
+    /// mistaking an unreachable worker for an idle, in-sync one.
+    pub async fn health_report(&mut self) -> WorkerHealth {
+        let is_serving = self.is_healthy().await;
+
+        match self.query_status().await {
+            Some((in_flight, queued, clock_drift)) => WorkerHealth {
+                is_serving,
+                in_flight,
+                queued,
+                last_success: self.last_success,
+                clock_drift,
+            },
+            // no status answer: treat the worker as unavailable and fully loaded so is_ready() is
+            // false and load() cannot win the least-loaded selection.
+            None => WorkerHealth {
+                is_serving: false,
+                in_flight: u32::MAX,
+                queued: u32::MAX,
+                last_success: self.last_success,
+                clock_drift: Duration::MAX,
+            },
+        }
+    }
+
+    /// Queries the worker's status endpoint, returning its in-flight/queued counts and an NTP-style
+    /// clock-drift estimate, or `None` if the query fails.
+    ///
+    /// The drift is computed against the midpoint of the request round-trip rather than the raw
+    /// response time, so request latency is not mistaken for skew: a slow-but-synchronized worker
+    /// reads as aligned, while a genuinely skewed clock still stands out.
+    async fn query_status(&self) -> Option<(u32, u32, Duration)> {
+        let mut client = ApiClient::connect(format!("http://{}", self.address()))
+            .await
+            .map_err(|err| error!("Failed to connect to worker status ({}): {}", self.address(), err))
+            .ok()?;
+
+        let sent_at = SystemTime::now();
+        let status = client
+            .status(StatusRequest {})
+            .await
+            .map_err(|err| error!("Failed to query worker status ({}): {}", self.address(), err))
+            .ok()?
+            .into_inner();
+        let received_at = SystemTime::now();
+
+        // the worker's reported time lines up with the round-trip midpoint if its clock is aligned.
+        let round_trip = received_at.duration_since(sent_at).unwrap_or(Duration::ZERO);
+        let midpoint = sent_at + round_trip / 2;
+        let coordinator_mid = midpoint.duration_since(UNIX_EPOCH).unwrap_or(Duration::ZERO);
+        let worker_now = Duration::from_millis(status.unix_time_millis);
+        let clock_drift = coordinator_mid.abs_diff(worker_now);
+
+        Some((status.in_flight, status.queued, clock_drift))
+    }
+
+    /// Records that the worker just completed a job successfully.
+    pub fn record_success(&mut self) {
+        self.last_success = Some(Instant::now());
+    }
+
     pub fn is_available(&self) -> bool {
         self.is_available
     }
@@ -0,0 +1,69 @@
+use alloc::string::String;
+use core::fmt;
+
+use miden_objects::{AccountDeltaError, ProvenTransactionError, TransactionOutputError};
+use vm_processor::ExecutionError;
+
+use crate::host::TransactionHostError;
+
+// TRANSACTION PROVER ERROR
+// ================================================================================================
+
+/// Errors returned while proving a transaction with a [TransactionProver](crate::TransactionProver).
+#[derive(Debug)]
+pub enum TransactionProverError {
+    /// Constructing the [TransactionHost](crate::TransactionHost) for the prover failed.
+    TransactionHostCreationFailed(TransactionHostError),
+    /// The transaction program failed to execute or prove.
+    TransactionProgramExecutionFailed(ExecutionError),
+    /// The transaction outputs could not be reconstructed from the proof.
+    TransactionOutputConstructionFailed(TransactionOutputError),
+    /// Applying the account delta to a new public account failed.
+    AccountDeltaApplyFailed(AccountDeltaError),
+    /// Assembling the [ProvenTransaction](miden_objects::transaction::ProvenTransaction) failed.
+    ProvenTransactionBuildFailed(ProvenTransactionError),
+    /// Proving did not complete within the configured deadline and was aborted.
+    Timeout,
+    /// A prover-specific error that does not map onto any of the variants above.
+    Other(String),
+}
+
+impl TransactionProverError {
+    /// Creates an [TransactionProverError::Other] error from anything that can be turned into a
+    /// string.
+    pub fn other(message: impl Into<String>) -> Self {
+        Self::Other(message.into())
+    }
+}
+
+impl fmt::Display for TransactionProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TransactionHostCreationFailed(err) => {
+                write!(f, "failed to create the transaction host: {err}")
+            },
+            Self::TransactionProgramExecutionFailed(err) => {
+                write!(f, "failed to execute the transaction program: {err}")
+            },
+            Self::TransactionOutputConstructionFailed(err) => {
+                write!(f, "failed to construct the transaction outputs: {err}")
+            },
+            Self::AccountDeltaApplyFailed(err) => {
+                write!(f, "failed to apply the account delta: {err}")
+            },
+            Self::ProvenTransactionBuildFailed(err) => {
+                write!(f, "failed to build the proven transaction: {err}")
+            },
+            Self::Timeout => write!(f, "transaction proving exceeded its deadline"),
+            Self::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl core::error::Error for TransactionProverError {}
+
+impl From<ExecutionError> for TransactionProverError {
+    fn from(err: ExecutionError) -> Self {
+        Self::TransactionProgramExecutionFailed(err)
+    }
+}
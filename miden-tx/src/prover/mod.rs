@@ -1,6 +1,10 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
 use alloc::{sync::Arc, vec::Vec};
+use core::time::Duration;
+
+#[cfg(feature = "async")]
+pub mod pending;
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
@@ -34,6 +38,28 @@ pub trait TransactionProver {
         &self,
         tx_witness: TransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError>;
+
+    /// Proves the provided transaction, aborting once `timeout` of wall-clock time has elapsed.
+    ///
+    /// When `timeout` is `None` this behaves exactly like [prove](Self::prove). Otherwise, proving
+    /// that exceeds the budget is aborted and [TransactionProverError::Timeout] is returned so the
+    /// caller can reclaim the stuck worker instead of blocking on it indefinitely.
+    ///
+    /// The default implementation ignores the deadline and delegates to [prove](Self::prove);
+    /// implementations that can bound their execution should override it.
+    ///
+    /// # Errors
+    /// - [TransactionProverError::Timeout] if proving does not complete within `timeout`.
+    /// - Any error that [prove](Self::prove) may return.
+    #[maybe_async]
+    fn prove_with_deadline(
+        &self,
+        tx_witness: TransactionWitness,
+        timeout: Option<Duration>,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let _ = timeout;
+        maybe_await!(self.prove(tx_witness))
+    }
 }
 
 // LOCAL TRANSACTION PROVER
@@ -80,6 +106,89 @@ impl TransactionProver for LocalTransactionProver {
     fn prove(
         &self,
         tx_witness: TransactionWitness,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        maybe_await!(Self::prove_witness(&self.mast_store, &self.proof_options, tx_witness))
+    }
+
+    #[maybe_async]
+    fn prove_with_deadline(
+        &self,
+        tx_witness: TransactionWitness,
+        timeout: Option<Duration>,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let Some(budget) = timeout else {
+            return maybe_await!(Self::prove_witness(
+                &self.mast_store,
+                &self.proof_options,
+                tx_witness
+            ));
+        };
+
+        // Under the async feature the proof is a future, so it can simply be raced against a
+        // timer: if the budget elapses first the future is dropped and the worker is freed.
+        #[cfg(feature = "async")]
+        {
+            tokio::time::timeout(
+                budget,
+                Self::prove_witness(&self.mast_store, &self.proof_options, tx_witness),
+            )
+            .await
+            .map_err(|_| TransactionProverError::Timeout)?
+        }
+
+        // In the blocking path the proof cannot yield, so it is run on a dedicated thread and the
+        // caller waits at most `budget` for the result. On timeout the caller returns immediately
+        // and reclaims its worker; the detached thread is released when the proof finally ends.
+        #[cfg(all(not(feature = "async"), feature = "std"))]
+        {
+            self.prove_blocking_with_deadline(tx_witness, budget)
+        }
+
+        // Without a runtime there is no wall clock to bound a non-yielding proof against, so the
+        // deadline cannot be enforced; proving runs to completion.
+        #[cfg(all(not(feature = "async"), not(feature = "std")))]
+        {
+            let _ = budget;
+            Self::prove_witness(&self.mast_store, &self.proof_options, tx_witness)
+        }
+    }
+}
+
+impl LocalTransactionProver {
+    /// Runs the proof on a dedicated thread and waits at most `budget` for it, returning
+    /// [TransactionProverError::Timeout] if the budget elapses first.
+    #[cfg(all(not(feature = "async"), feature = "std"))]
+    fn prove_blocking_with_deadline(
+        &self,
+        tx_witness: TransactionWitness,
+        budget: Duration,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        use std::sync::mpsc::{self, RecvTimeoutError};
+
+        let mast_store = self.mast_store.clone();
+        let proof_options = self.proof_options.clone();
+        let (result_tx, result_rx) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            let result = Self::prove_witness(&mast_store, &proof_options, tx_witness);
+            // the receiver is gone if the caller already timed out; the result is simply dropped.
+            let _ = result_tx.send(result);
+        });
+
+        match result_rx.recv_timeout(budget) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => Err(TransactionProverError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => {
+                Err(TransactionProverError::other("proving thread terminated unexpectedly"))
+            },
+        }
+    }
+
+    #[maybe_async]
+    fn prove_witness(
+        mast_store: &Arc<TransactionMastStore>,
+        proof_options: &ProvingOptions,
+        tx_witness: TransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError> {
         let TransactionWitness {
             tx_inputs,
@@ -90,7 +199,7 @@ impl TransactionProver for LocalTransactionProver {
 
         for account_code in &account_codes {
             // load the code mast forest to the mast store
-            self.mast_store.load_account_code(account_code);
+            mast_store.load_account_code(account_code);
         }
 
         let account = tx_inputs.account();
@@ -103,12 +212,12 @@ impl TransactionProver for LocalTransactionProver {
         let advice_provider: MemAdviceProvider = advice_inputs.into();
 
         // load the store with account/note/tx_script MASTs
-        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+        mast_store.load_transaction_code(&tx_inputs, &tx_args);
 
         let mut host: TransactionHost<_> = TransactionHost::new(
             account.into(),
             advice_provider,
-            self.mast_store.clone(),
+            mast_store.clone(),
             None,
             account_codes.iter().map(|c| c.commitment()).collect(),
         )
@@ -118,7 +227,7 @@ impl TransactionProver for LocalTransactionProver {
             &TransactionKernel::main(),
             stack_inputs,
             &mut host,
-            self.proof_options.clone()
+            proof_options.clone()
         ))
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
@@ -0,0 +1,272 @@
+//! End-to-end submission and confirmation for a proven transaction.
+//!
+//! This module drives network IO and wall-clock polling, so it is compiled only under the crate's
+//! `async` feature. It uses neither `thiserror` nor `async_trait`, and it does not call `tokio`
+//! itself: the poll delay between node queries is owned by the [NodeClient], which already supplies
+//! the runtime's IO and timers.
+
+use alloc::string::{String, ToString};
+use core::{fmt, time::Duration};
+
+use miden_objects::{
+    BlockNumber,
+    transaction::{ProvenTransaction, TransactionId},
+};
+
+// NODE CLIENT
+// ================================================================================================
+
+/// The interface a [PendingProvenTransaction] uses to talk to a node: submit a proven transaction,
+/// read the current chain tip, and observe whether the transaction's account update has been
+/// included.
+#[allow(async_fn_in_trait)]
+pub trait NodeClient {
+    /// Submits a proven transaction to the node, returning its [TransactionId].
+    async fn submit(&self, tx: &ProvenTransaction)
+    -> Result<TransactionId, PendingTransactionError>;
+
+    /// Returns the number of the current chain tip.
+    async fn tip_block_num(&self) -> Result<BlockNumber, PendingTransactionError>;
+
+    /// Returns the block number at which the transaction's account update was included, or `None`
+    /// if it has not been observed yet.
+    async fn inclusion_block_num(
+        &self,
+        tx: &ProvenTransaction,
+    ) -> Result<Option<BlockNumber>, PendingTransactionError>;
+
+    /// Returns whether the node has dropped the transaction from its mempool.
+    async fn is_dropped(&self, tx_id: TransactionId) -> Result<bool, PendingTransactionError>;
+
+    /// Waits `duration` before the next poll. Owned by the client so this module does not pull in a
+    /// concrete async runtime (e.g. the gRPC client backs this with `tokio::time::sleep`).
+    async fn sleep(&self, duration: Duration);
+}
+
+// PENDING TRANSACTION STATE
+// ================================================================================================
+
+/// The state of a proven transaction as it moves from submission to confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionState {
+    /// The transaction has not been submitted to the node yet.
+    Submitting,
+    /// The transaction has been accepted into the node's mempool under the given id.
+    Submitted(TransactionId),
+    /// The transaction's account update has been observed at the requested confirmation depth.
+    Confirmed(BlockNumber),
+    /// The transaction was dropped from the mempool past its expiration and cannot be re-submitted.
+    Dropped,
+    /// Submission or confirmation failed terminally.
+    Failed(String),
+}
+
+/// Error returned by [NodeClient] operations and surfaced through [PendingProvenTransaction].
+#[derive(Debug)]
+pub enum PendingTransactionError {
+    /// Submitting the transaction to the node failed.
+    SubmissionFailed(String),
+    /// Querying the node failed.
+    QueryFailed(String),
+    /// The transaction was dropped from the mempool past its expiration block.
+    Dropped,
+}
+
+impl fmt::Display for PendingTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SubmissionFailed(msg) => {
+                write!(f, "failed to submit transaction to the node: {msg}")
+            },
+            Self::QueryFailed(msg) => write!(f, "failed to query the node: {msg}"),
+            Self::Dropped => write!(f, "transaction was dropped before it could be confirmed"),
+        }
+    }
+}
+
+impl core::error::Error for PendingTransactionError {}
+
+// POLL ACTION
+// ================================================================================================
+
+/// The action the confirmation loop should take after a single poll of the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PollAction {
+    /// The update was observed at the given block, deep enough to be confirmed.
+    Confirmed(BlockNumber),
+    /// The transaction was dropped but is still valid, so it should be re-submitted.
+    Resubmit,
+    /// The transaction was dropped and has expired; it can no longer be confirmed.
+    Expired,
+    /// Nothing actionable yet; keep polling.
+    Wait,
+}
+
+/// Decides what to do after a poll, given the observed inclusion block (if any), the current chain
+/// tip, whether the transaction was dropped, the required confirmation depth, and the transaction's
+/// expiration block. Kept pure so the confirmation policy can be tested without a node.
+fn evaluate(
+    included: Option<BlockNumber>,
+    tip: BlockNumber,
+    dropped: bool,
+    required_depth: u32,
+    expiration: BlockNumber,
+) -> PollAction {
+    if let Some(included_at) = included {
+        let depth = tip.as_u32().saturating_sub(included_at.as_u32());
+        if depth >= required_depth {
+            PollAction::Confirmed(included_at)
+        } else {
+            PollAction::Wait
+        }
+    } else if dropped {
+        if tip >= expiration {
+            PollAction::Expired
+        } else {
+            PollAction::Resubmit
+        }
+    } else {
+        PollAction::Wait
+    }
+}
+
+// PENDING PROVEN TRANSACTION
+// ================================================================================================
+
+/// Drives a [ProvenTransaction] through submission and confirmation against a node, so a client can
+/// await a single future instead of hand-rolling polling loops around the prover output.
+///
+/// The transaction advances through [TransactionState]: `Submitting -> Submitted(tx_id) ->
+/// Confirmed(block_num)`, or `Dropped`/`Failed`. The node is polled every `poll_interval`; if the
+/// transaction is dropped from the mempool before its expiration block, it is re-submitted.
+pub struct PendingProvenTransaction<C> {
+    proven_tx: ProvenTransaction,
+    client: C,
+    poll_interval: Duration,
+    state: TransactionState,
+}
+
+impl<C: NodeClient> PendingProvenTransaction<C> {
+    /// Creates a pending transaction that will poll `client` every `poll_interval`.
+    pub fn new(proven_tx: ProvenTransaction, client: C, poll_interval: Duration) -> Self {
+        Self {
+            proven_tx,
+            client,
+            poll_interval,
+            state: TransactionState::Submitting,
+        }
+    }
+
+    /// Returns the current state of the transaction.
+    pub fn state(&self) -> &TransactionState {
+        &self.state
+    }
+
+    /// Resolves once the transaction's account update has been observed at least `n` blocks deep,
+    /// re-submitting if the transaction is dropped before its expiration block.
+    ///
+    /// Returns the block number at which the update was included, or a [PendingTransactionError] if
+    /// the transaction is dropped past expiration or the node cannot be reached.
+    pub async fn confirmations(&mut self, n: u32) -> Result<BlockNumber, PendingTransactionError> {
+        let tx_id = self.ensure_submitted().await?;
+        let expiration = self.proven_tx.expiration_block_num();
+
+        loop {
+            let included = self.client.inclusion_block_num(&self.proven_tx).await?;
+            let tip = self.client.tip_block_num().await?;
+            let dropped = included.is_none() && self.client.is_dropped(tx_id).await?;
+
+            match evaluate(included, tip, dropped, n, expiration) {
+                PollAction::Confirmed(included_at) => {
+                    self.state = TransactionState::Confirmed(included_at);
+                    return Ok(included_at);
+                },
+                PollAction::Expired => {
+                    self.state = TransactionState::Dropped;
+                    return Err(PendingTransactionError::Dropped);
+                },
+                PollAction::Resubmit => {
+                    self.resubmit().await?;
+                },
+                PollAction::Wait => {},
+            }
+
+            self.client.sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Submits the transaction if it has not been submitted yet and records the id.
+    async fn ensure_submitted(&mut self) -> Result<TransactionId, PendingTransactionError> {
+        if let TransactionState::Submitted(tx_id) = &self.state {
+            return Ok(*tx_id);
+        }
+        self.resubmit().await
+    }
+
+    async fn resubmit(&mut self) -> Result<TransactionId, PendingTransactionError> {
+        match self.client.submit(&self.proven_tx).await {
+            Ok(tx_id) => {
+                self.state = TransactionState::Submitted(tx_id);
+                Ok(tx_id)
+            },
+            Err(err) => {
+                self.state = TransactionState::Failed(err.to_string());
+                Err(err)
+            },
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(n: u32) -> BlockNumber {
+        BlockNumber::from(n)
+    }
+
+    #[test]
+    fn not_included_and_live_keeps_waiting() {
+        // not yet included, not dropped => keep polling.
+        assert_eq!(evaluate(None, block(10), false, 2, block(100)), PollAction::Wait);
+    }
+
+    #[test]
+    fn included_but_too_shallow_keeps_waiting() {
+        // included one block deep but two confirmations are required.
+        assert_eq!(
+            evaluate(Some(block(10)), block(11), false, 2, block(100)),
+            PollAction::Wait
+        );
+    }
+
+    #[test]
+    fn included_deep_enough_confirms() {
+        assert_eq!(
+            evaluate(Some(block(10)), block(12), false, 2, block(100)),
+            PollAction::Confirmed(block(10))
+        );
+    }
+
+    #[test]
+    fn dropped_before_expiration_resubmits() {
+        assert_eq!(evaluate(None, block(50), true, 2, block(100)), PollAction::Resubmit);
+    }
+
+    #[test]
+    fn dropped_past_expiration_expires() {
+        assert_eq!(evaluate(None, block(100), true, 2, block(100)), PollAction::Expired);
+    }
+
+    #[test]
+    fn inclusion_takes_precedence_over_a_stale_dropped_flag() {
+        // once the update is observed, a lingering dropped signal is irrelevant.
+        assert_eq!(
+            evaluate(Some(block(10)), block(20), true, 2, block(5)),
+            PollAction::Confirmed(block(10))
+        );
+    }
+}